@@ -0,0 +1,33 @@
+//! Error types shared across the Nexus addon.
+
+use std::fmt;
+
+/// Errors that can occur while initializing, running, or tearing down the
+/// Nexus addon.
+#[derive(Debug)]
+pub enum NexusError {
+    /// The exe manager or one of its dependent resources failed to initialize.
+    ManagerInitialization(String),
+    /// Spawning, signalling, or waiting on the managed process failed.
+    ProcessControl(String),
+    /// A post-init, user-triggered recovery action (e.g. opening the
+    /// download page for a missing resource) failed.
+    ResourceRecovery(String),
+}
+
+impl fmt::Display for NexusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NexusError::ManagerInitialization(msg) => {
+                write!(f, "manager initialization failed: {msg}")
+            }
+            NexusError::ProcessControl(msg) => write!(f, "process control failed: {msg}"),
+            NexusError::ResourceRecovery(msg) => write!(f, "resource recovery failed: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for NexusError {}
+
+/// Convenience result alias used throughout the Nexus addon.
+pub type Result<T> = std::result::Result<T, NexusError>;
@@ -29,10 +29,11 @@ All initialization steps are performed with error handling and logging. Resource
 
 #[cfg(feature = "nexus")]
 use nexus::{
-    keybind::register_keybind_with_string,
+    alert::alert,
+    keybind::{deregister_keybind, register_keybind_with_string},
     keybind_handler,
     paths::get_addon_dir,
-    //quick_access::add_quick_access,
+    quick_access::{add_quick_access, remove_quick_access},
     texture::{RawTextureReceiveCallback, load_texture_from_memory},
     texture_receive,
 };
@@ -41,7 +42,131 @@ use nexus::{
 use windows::Win32::{Foundation::HINSTANCE, System::LibraryLoader::GetModuleHandleW};
 
 #[cfg(feature = "nexus")]
-use crate::nexus_addon::{NexusError, Result, manager::ExeManager, ui};
+use std::collections::HashSet;
+#[cfg(feature = "nexus")]
+use std::sync::Mutex;
+
+#[cfg(feature = "nexus")]
+use crate::nexus_addon::{
+    NexusError, Result,
+    manager::ExeManager,
+    resources::{self, MISSING_RESOURCE},
+    settings::{SETTINGS, Settings},
+    ui,
+};
+
+/// Identifiers this addon has recorded just before passing them to a Nexus
+/// registration call. The Nexus
+/// registration calls don't return a `Result`, so this is how
+/// `try_register` below detects a duplicate registration.
+#[cfg(feature = "nexus")]
+static REGISTERED_IDS: Mutex<Option<HashSet<String>>> = Mutex::new(None);
+
+/// Records `id` as registered, returning an error if it was already present.
+#[cfg(feature = "nexus")]
+fn try_register(id: &str) -> Result<()> {
+    let mut registered = REGISTERED_IDS.lock().map_err(|e| {
+        NexusError::ManagerInitialization(format!("Failed to lock registered-id set: {e}"))
+    })?;
+    let registered = registered.get_or_insert_with(HashSet::new);
+
+    if !registered.insert(id.to_string()) {
+        return Err(NexusError::ManagerInitialization(format!(
+            "'{id}' is already registered"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Forgets `id` so it can be registered again (used during rollback).
+#[cfg(feature = "nexus")]
+fn forget_registration(id: &str) {
+    if let Ok(mut registered) = REGISTERED_IDS.lock() {
+        if let Some(registered) = registered.as_mut() {
+            registered.remove(id);
+        }
+    }
+}
+
+/// Tracks which initialization steps have completed so `initialize_nexus_addon`
+/// can unwind them (in the order given by [`InitGuard::rollback`]) if a later
+/// step fails, mirroring a
+/// "create-or-fail-clean" transaction. This includes clearing the `SETTINGS`
+/// and `EXE_MANAGER` globals, so a failed load leaves the addon retryable by
+/// a later `nexus_load()` rather than permanently wedged.
+#[cfg(feature = "nexus")]
+#[derive(Default)]
+struct InitGuard {
+    /// Whether the global `SETTINGS` has been set.
+    settings_set: bool,
+    /// Texture ids successfully passed to `load_texture_from_memory`. Nexus
+    /// exposes no texture-unregistration call, so rollback can only forget
+    /// them from `REGISTERED_IDS` (letting a retried load re-register them);
+    /// the underlying GPU texture stays resident until the DLL unloads.
+    texture_ids: Vec<String>,
+    quick_access_id: Option<String>,
+    keybind_id: Option<String>,
+    exe_manager_set: bool,
+    attached: bool,
+}
+
+#[cfg(feature = "nexus")]
+impl InitGuard {
+    /// Reverts every step recorded so far: detaches, stops and clears the exe
+    /// manager, then undoes the keybind, quick access, textures and settings.
+    /// This is the reverse of the recording order except for the exe manager,
+    /// which is recorded second but undone right after detaching.
+    fn rollback(&self) {
+        if self.attached {
+            log::warn!("Rolling back nexus addon init: detaching");
+            crate::detatch();
+        }
+
+        if self.exe_manager_set {
+            log::warn!("Rolling back nexus addon init: stopping and clearing exe manager");
+            let exe_manager_arc = crate::nexus_addon::manager::EXE_MANAGER
+                .lock()
+                .ok()
+                .and_then(|mut global| global.take());
+
+            if let Some(exe_manager_arc) = exe_manager_arc {
+                match exe_manager_arc.lock() {
+                    Ok(mut exe_manager) => {
+                        if let Err(e) = exe_manager.stop_exe() {
+                            log::error!("Failed to stop exe manager during rollback: {e}");
+                        }
+                    }
+                    Err(e) => log::error!("Failed to lock exe manager during rollback: {e}"),
+                }
+            }
+        }
+
+        if let Some(keybind_id) = &self.keybind_id {
+            log::warn!("Rolling back nexus addon init: deregistering keybind");
+            deregister_keybind(keybind_id);
+            forget_registration(keybind_id);
+        }
+
+        if let Some(quick_access_id) = &self.quick_access_id {
+            log::warn!("Rolling back nexus addon init: reverting quick access");
+            remove_quick_access(quick_access_id);
+            forget_registration(quick_access_id);
+        }
+
+        for texture_id in &self.texture_ids {
+            log::warn!("Rolling back nexus addon init: forgetting texture '{texture_id}'");
+            forget_registration(texture_id);
+        }
+
+        if self.settings_set {
+            log::warn!("Rolling back nexus addon init: clearing global settings");
+            if let Ok(mut settings) = SETTINGS.lock() {
+                settings.take();
+            }
+        }
+    }
+}
 
 /// Returns the HMODULE and casts it into HINSTANCE
 /// On modern systems, HMODULE is pretty much the same as HINSTANCE, and can be safely cast
@@ -63,7 +188,12 @@ pub fn nexus_load() {
     log::info!("Blish HUD overlay loader addon loaded successfully");
 }
 
-/// Internal initialization function with proper error handling
+/// Internal initialization function with proper error handling.
+///
+/// Steps are performed in order and recorded in an [`InitGuard`] as they
+/// complete. If any step fails, everything recorded so far is unwound by
+/// [`InitGuard::rollback`] before the error is returned, so a failed load never leaves
+/// a half-registered addon behind.
 #[cfg(feature = "nexus")]
 fn initialize_nexus_addon() -> Result<()> {
     // Initialize the nexus menus and options
@@ -78,35 +208,81 @@ fn initialize_nexus_addon() -> Result<()> {
         NexusError::ManagerInitialization(format!("Failed to create addon directory: {e}"))
     })?;
 
-    // Initialize the exe manager
-    let exe_manager = std::sync::Arc::new(std::sync::Mutex::new(ExeManager::new(addon_dir)?));
+    let mut guard = InitGuard::default();
 
-    crate::nexus_addon::manager::EXE_MANAGER
-        .set(exe_manager.clone())
-        .map_err(|_| {
-            NexusError::ManagerInitialization("Failed to set global exe manager".to_string())
-        })?;
+    if let Err(e) = try_initialize(&addon_dir, &mut guard) {
+        guard.rollback();
+        return Err(e);
+    }
 
-    // Launch exe on startup if enabled
+    Ok(())
+}
+
+/// Performs the steps of [`initialize_nexus_addon`], recording each
+/// completed step in `guard` as it succeeds.
+#[cfg(feature = "nexus")]
+fn try_initialize(addon_dir: &std::path::Path, guard: &mut InitGuard) -> Result<()> {
+    // Load settings before anything else so every later step can read from
+    // them instead of hardcoded literals.
+    let settings = Settings::load(addon_dir);
+    let (launch_on_startup, exe_name, keybind, quick_access_id, keybind_id) = (
+        settings.launch_on_startup,
+        settings.exe_name.clone(),
+        settings.keybind.clone(),
+        settings.quick_access_id.clone(),
+        settings.keybind_id.clone(),
+    );
+    *SETTINGS.lock().map_err(|e| {
+        NexusError::ManagerInitialization(format!("Failed to lock global settings: {e}"))
+    })? = Some(settings);
+    guard.settings_set = true;
+
+    // Initialize the exe manager
+    let exe_manager = std::sync::Arc::new(std::sync::Mutex::new(ExeManager::new(
+        addon_dir.to_path_buf(),
+        &exe_name,
+        launch_on_startup,
+    )?));
+
+    *crate::nexus_addon::manager::EXE_MANAGER.lock().map_err(|e| {
+        NexusError::ManagerInitialization(format!("Failed to lock global exe manager: {e}"))
+    })? = Some(exe_manager.clone());
+    guard.exe_manager_set = true;
+
+    // Verify the managed executable is actually present before trying to
+    // launch it on startup. A missing file is common on first install and
+    // isn't a hard failure: surface it and let the user fix it.
     {
         let mut manager = exe_manager.lock().map_err(|e| {
             NexusError::ManagerInitialization(format!("Failed to lock exe manager: {e}"))
         })?;
-        if *manager.launch_on_startup() {
+
+        if let Some(missing) = resources::verify_required_resources(manager.exe_path()) {
+            log::warn!("Missing required resource: {}", missing.path.display());
+            alert(&format!(
+                "Blish HUD overlay loader: {} is missing. Click the quick access icon to download it.",
+                missing.path.display()
+            ));
+            let _ = MISSING_RESOURCE.set(missing);
+        } else if *manager.launch_on_startup() {
             if let Err(e) = manager.launch_exe() {
                 log::error!("Failed to launch exe on startup: {e}");
+            } else {
+                log::info!("Managed process status: {:?}", manager.process_status());
             }
         }
     }
 
     // Load textures for the addon
-    load_addon_textures()?;
+    load_addon_textures(guard)?;
 
     // Setup quick access menu
-    setup_quick_access()?;
+    setup_quick_access(&quick_access_id, &keybind_id)?;
+    guard.quick_access_id = Some(quick_access_id);
 
     // Setup keybinds
-    setup_keybinds()?;
+    setup_keybinds(&keybind_id, &keybind)?;
+    guard.keybind_id = Some(keybind_id);
 
     // Setup UI rendering
     ui::setup_main_window_rendering();
@@ -115,13 +291,19 @@ fn initialize_nexus_addon() -> Result<()> {
     let hinstance = get_hinstance();
     log::info!("Loading via Nexus - HMODULE/HINSTANCE: {}", hinstance.0);
     crate::attach(hinstance);
+    guard.attached = true;
 
     Ok(())
 }
 
-/// Loads the addon textures from embedded resources
+/// Loads the addon textures from embedded resources.
+///
+/// `load_texture_from_memory` doesn't return a `Result`, so [`try_register`]
+/// is used to turn a duplicate registration into a `NexusError` instead of
+/// silently re-registering. Each id that's successfully registered is
+/// recorded in `guard` so a later step failing can unwind it.
 #[cfg(feature = "nexus")]
-fn load_addon_textures() -> Result<()> {
+fn load_addon_textures(guard: &mut InitGuard) -> Result<()> {
     let icon = include_bytes!("./images/64p_nexus_blish_loader.png");
     let icon_hover = include_bytes!("./images/64p_nexus_blish_loader.png");
 
@@ -129,29 +311,36 @@ fn load_addon_textures() -> Result<()> {
         log::info!("texture {id} loaded");
     });
 
-    // Note: load_texture_from_memory doesn't return a Result, so we assume success
-    // In a real implementation, we might want to add validation
+    try_register("BLISH_OVERLAY_LOADER_ICON")?;
     load_texture_from_memory("BLISH_OVERLAY_LOADER_ICON", icon, Some(receive_texture));
+    guard.texture_ids.push("BLISH_OVERLAY_LOADER_ICON".to_string());
+
+    try_register("BLISH_OVERLAY_LOADER_ICON_HOVER")?;
     load_texture_from_memory(
         "BLISH_OVERLAY_LOADER_ICON_HOVER",
         icon_hover,
         Some(receive_texture),
     );
+    guard.texture_ids.push("BLISH_OVERLAY_LOADER_ICON_HOVER".to_string());
 
     log::info!("Addon textures loaded successfully");
     Ok(())
 }
 
-/// Sets up the quick access menu entry
+/// Sets up the quick access menu entry.
+///
+/// `add_quick_access` doesn't return a `Result`, so [`try_register`] is used
+/// to turn a duplicate registration into a `NexusError` instead of silently
+/// re-registering.
 #[cfg(feature = "nexus")]
-fn setup_quick_access() -> Result<()> {
-    // Note: add_quick_access doesn't return a Result, so we assume success
-    // In a real implementation, we might want to add validation
+fn setup_quick_access(quick_access_id: &str, keybind_id: &str) -> Result<()> {
+    try_register(quick_access_id)?;
+
     add_quick_access(
-        "BLISH_OVERLAY_LOADER_SHORTCUT",
+        quick_access_id,
         "BLISH_OVERLAY_LOADER_ICON",
         "BLISH_OVERLAY_LOADER_ICON_HOVER",
-        "BLISH_OVERLAY_LOADER_KEYBIND",
+        keybind_id,
         "Blish HUD overlay loader",
     )
     .revert_on_unload();
@@ -160,27 +349,34 @@ fn setup_quick_access() -> Result<()> {
     Ok(())
 }
 
-/// Sets up the keybind handlers
+/// Sets up the keybind handlers.
+///
+/// `register_keybind_with_string` doesn't return a `Result`, so
+/// [`try_register`] is used to turn a duplicate registration into a
+/// `NexusError` instead of silently re-registering.
 #[cfg(feature = "nexus")]
-fn setup_keybinds() -> Result<()> {
+fn setup_keybinds(keybind_id: &str, keybind: &str) -> Result<()> {
+    try_register(keybind_id)?;
+
     let main_window_keybind_handler = keybind_handler!(|id, is_release| {
         log::info!(
             "keybind {id} {}",
             if is_release { "released" } else { "pressed" }
         );
         if !is_release {
-            ui::toggle_window();
+            match MISSING_RESOURCE.get() {
+                Some(missing) => {
+                    if let Err(e) = resources::open_download_page(missing.download_url) {
+                        log::error!("Failed to open download page: {e}");
+                    }
+                }
+                None => ui::toggle_window(),
+            }
         }
     });
 
-    // Note: register_keybind_with_string doesn't return a Result, so we assume success
-    // In a real implementation, we might want to add validation
-    register_keybind_with_string(
-        "BLISH_OVERLAY_LOADER_KEYBIND",
-        main_window_keybind_handler,
-        "ALT+SHIFT+1",
-    )
-    .revert_on_unload();
+    register_keybind_with_string(keybind_id, main_window_keybind_handler, keybind)
+        .revert_on_unload();
 
     log::info!("Keybinds setup successfully");
     Ok(())
@@ -201,8 +397,19 @@ pub fn nexus_unload() {
 /// Internal cleanup function with proper error handling
 #[cfg(feature = "nexus")]
 fn cleanup_nexus_addon() -> Result<()> {
-    // Stop all running executables before unloading
-    if let Some(exe_manager_arc) = crate::nexus_addon::manager::EXE_MANAGER.get() {
+    // Stop all running executables before unloading. Taking the global here
+    // (rather than just reading it) clears it so a later `nexus_load()` can
+    // set it again instead of failing on an already-set handle.
+    let exe_manager_arc = crate::nexus_addon::manager::EXE_MANAGER
+        .lock()
+        .map_err(|e| {
+            NexusError::ManagerInitialization(format!(
+                "Failed to lock global exe manager during cleanup: {e}"
+            ))
+        })?
+        .take();
+
+    if let Some(exe_manager_arc) = exe_manager_arc {
         let mut exe_manager = exe_manager_arc.lock().map_err(|e| {
             NexusError::ManagerInitialization(format!(
                 "Failed to lock exe manager during cleanup: {e}"
@@ -211,9 +418,35 @@ fn cleanup_nexus_addon() -> Result<()> {
         exe_manager.stop_exe()?;
     }
 
+    // Persist settings one last time before unloading, clearing the global
+    // for the same reason as the exe manager above.
+    let settings = SETTINGS
+        .lock()
+        .map_err(|e| {
+            NexusError::ManagerInitialization(format!(
+                "Failed to lock global settings during cleanup: {e}"
+            ))
+        })?
+        .take();
+
+    if let Some(settings) = settings {
+        match get_addon_dir("LOADER_public") {
+            Some(addon_dir) => settings.save(&addon_dir),
+            None => log::error!("Failed to get addon directory during cleanup"),
+        }
+    }
+
     // Perform main cleanup
     crate::detatch();
 
+    // Nexus reverts quick access/keybind registrations on unload via
+    // `.revert_on_unload()`; textures have no such call but are forgotten
+    // here too (and the GPU slot is reclaimed when the DLL unloads), so the
+    // next load is free to register all of them again.
+    if let Ok(mut registered) = REGISTERED_IDS.lock() {
+        registered.take();
+    }
+
     log::info!("Nexus addon cleanup completed successfully");
     Ok(())
 }
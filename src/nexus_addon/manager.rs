@@ -0,0 +1,335 @@
+/*!
+# Exe Manager
+
+Owns the lifecycle of the external Blish HUD overlay executable: spawning it,
+draining its stdout/stderr onto the log, restarting it on an unexpected exit
+according to a [`RestartPolicy`], and stopping it cleanly on addon unload.
+*/
+
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use os_pipe::pipe;
+use shared_child::SharedChild;
+
+use crate::nexus_addon::{NexusError, Result};
+
+/// Global handle to the running exe manager, set during
+/// `initialize_nexus_addon` and taken again on rollback or unload. Unlike a
+/// `OnceLock`, this can be cleared back to `None` — `InitGuard::rollback`
+/// does so on a failed init, and `cleanup_nexus_addon` does so on unload —
+/// so a later `nexus_load()` can set it again instead of being permanently
+/// wedged by a stale value.
+pub static EXE_MANAGER: Mutex<Option<Arc<Mutex<ExeManager>>>> = Mutex::new(None);
+
+/// How the manager should react to the managed process exiting on its own.
+#[derive(Debug, Clone, Copy)]
+pub struct RestartPolicy {
+    /// Maximum number of automatic restarts before giving up.
+    pub max_retries: u32,
+    /// Minimum time that must elapse between a launch and the next restart.
+    pub backoff: Duration,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            backoff: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Observable state of the managed process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessStatus {
+    /// No process is being supervised: none has been launched yet, it was
+    /// stopped deliberately, or it exited and is not being restarted.
+    NotRunning,
+    /// The process is alive and being supervised.
+    Running,
+    /// The process exited on its own with the given exit code. Reported only
+    /// while a restart is pending; once the supervisor gives up, or the exit
+    /// was clean, the status is `NotRunning`.
+    Exited(i32),
+    /// The process terminated abnormally, with no exit code available.
+    /// Reported only while a restart is pending, as for `Exited`.
+    Crashed,
+}
+
+/// Process handle and status shared between [`ExeManager`] and its
+/// background supervisor thread.
+struct Supervised {
+    child: Arc<SharedChild>,
+    status: Mutex<ProcessStatus>,
+}
+
+/// The currently-supervised process (if any) and whether the manager has
+/// been asked to stop. Both live behind the same lock so that "take the
+/// current process and flag a stop" (in [`ExeManager::stop_exe`]) and
+/// "check the stop flag before installing a freshly-restarted process" (in
+/// `supervise`) happen as a single atomic step — otherwise a restart landing
+/// in the gap between those two checks could install a process that
+/// `stop_exe` has already finished waiting on, and the supervisor thread
+/// would then block forever on it.
+struct SharedState {
+    current: Option<Arc<Supervised>>,
+    stopping: bool,
+}
+
+/// Supervises a single instance of the external executable.
+pub struct ExeManager {
+    addon_dir: PathBuf,
+    exe_path: PathBuf,
+    launch_on_startup: bool,
+    restart_policy: RestartPolicy,
+    shared: Arc<Mutex<SharedState>>,
+    supervisor: Option<JoinHandle<()>>,
+}
+
+impl ExeManager {
+    /// Creates a manager for `exe_name` expected inside `addon_dir`, honoring
+    /// the persisted `launch_on_startup` setting.
+    ///
+    /// This does not launch the process; call [`ExeManager::launch_exe`] to
+    /// do so.
+    pub fn new(addon_dir: PathBuf, exe_name: &str, launch_on_startup: bool) -> Result<Self> {
+        let exe_path = addon_dir.join(exe_name);
+
+        Ok(Self {
+            addon_dir,
+            exe_path,
+            launch_on_startup,
+            restart_policy: RestartPolicy::default(),
+            shared: Arc::new(Mutex::new(SharedState {
+                current: None,
+                stopping: false,
+            })),
+            supervisor: None,
+        })
+    }
+
+    /// Whether the executable should be launched automatically on addon load.
+    pub fn launch_on_startup(&self) -> &bool {
+        &self.launch_on_startup
+    }
+
+    /// Path of the executable this manager launches and supervises.
+    pub fn exe_path(&self) -> &std::path::Path {
+        &self.exe_path
+    }
+
+    /// Current observable state of the managed process.
+    pub fn process_status(&self) -> ProcessStatus {
+        match self.shared.lock().unwrap().current.as_ref() {
+            Some(supervised) => *supervised.status.lock().unwrap(),
+            None => ProcessStatus::NotRunning,
+        }
+    }
+
+    /// Launches the executable if it isn't already running, wiring up piped
+    /// stdout/stderr capture and a background supervisor thread that applies
+    /// the manager's [`RestartPolicy`] on unexpected exit.
+    ///
+    /// `shared.current` is cleared back to `None` by `supervise()` whenever it
+    /// returns without installing a replacement process (clean exit, a crash
+    /// that exhausted `max_retries`, or a failed restart spawn), so presence
+    /// of a `Supervised` record here means the process is either running or
+    /// exited and waiting out the backoff before the supervisor restarts it,
+    /// and this is a reliable "already launched" guard.
+    pub fn launch_exe(&mut self) -> Result<()> {
+        if self.shared.lock().unwrap().current.is_some() {
+            return Ok(());
+        }
+
+        let supervised = spawn_child(&self.exe_path, &self.addon_dir)?;
+        {
+            let mut shared = self.shared.lock().unwrap();
+            shared.stopping = false;
+            shared.current = Some(supervised);
+        }
+
+        let shared = self.shared.clone();
+        let addon_dir = self.addon_dir.clone();
+        let exe_path = self.exe_path.clone();
+        let restart_policy = self.restart_policy;
+
+        self.supervisor = Some(thread::spawn(move || {
+            supervise(shared, addon_dir, exe_path, restart_policy, 0);
+        }));
+
+        Ok(())
+    }
+
+    /// Stops the managed process and joins the supervisor thread, closing its
+    /// stdout/stderr pipes in the process.
+    pub fn stop_exe(&mut self) -> Result<()> {
+        let supervised = {
+            let mut shared = self.shared.lock().unwrap();
+            shared.stopping = true;
+            shared.current.take()
+        };
+
+        if let Some(supervised) = supervised {
+            if let Err(e) = supervised.child.kill() {
+                log::warn!("Failed to kill managed process: {e}");
+            }
+            let _ = supervised.child.wait();
+            *supervised.status.lock().unwrap() = ProcessStatus::NotRunning;
+        }
+
+        if let Some(handle) = self.supervisor.take() {
+            if handle.join().is_err() {
+                log::warn!("Supervisor thread for managed process panicked");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Spawns the executable with piped stdout/stderr and starts threads that
+/// drain each pipe into `log::`.
+fn spawn_child(exe_path: &PathBuf, addon_dir: &PathBuf) -> Result<Arc<Supervised>> {
+    let (stdout_reader, stdout_writer) = pipe()
+        .map_err(|e| NexusError::ProcessControl(format!("failed to create stdout pipe: {e}")))?;
+    let (stderr_reader, stderr_writer) = pipe()
+        .map_err(|e| NexusError::ProcessControl(format!("failed to create stderr pipe: {e}")))?;
+
+    let mut command = std::process::Command::new(exe_path);
+    command
+        .current_dir(addon_dir)
+        .stdout(stdout_writer)
+        .stderr(stderr_writer);
+
+    let child = SharedChild::spawn(&mut command).map_err(|e| {
+        NexusError::ProcessControl(format!("failed to spawn {}: {e}", exe_path.display()))
+    })?;
+
+    drain_pipe_to_log(BufReader::new(stdout_reader), "stdout");
+    drain_pipe_to_log(BufReader::new(stderr_reader), "stderr");
+
+    Ok(Arc::new(Supervised {
+        child: Arc::new(child),
+        status: Mutex::new(ProcessStatus::Running),
+    }))
+}
+
+fn drain_pipe_to_log(reader: BufReader<impl std::io::Read + Send + 'static>, stream_name: &'static str) {
+    thread::spawn(move || {
+        for line in reader.lines() {
+            match line {
+                Ok(line) => log::info!("[Blish HUD {stream_name}] {line}"),
+                Err(e) => {
+                    log::warn!("Error reading {stream_name} from managed process: {e}");
+                    break;
+                }
+            }
+        }
+    });
+}
+
+/// Waits on the managed process and, if it exited unexpectedly and the
+/// manager hasn't been stopped deliberately, relaunches it according to
+/// `restart_policy` until `max_retries` is exhausted.
+///
+/// `shared.current` is read at the top of every iteration (rather than
+/// threading a plain `Arc<Supervised>` through the recursion) so that
+/// `ExeManager::process_status`/`stop_exe` always observe the process this
+/// thread is actually supervising, including across restarts. Installing a
+/// restarted process happens in the same locked section as checking
+/// `shared.stopping`, so a `stop_exe()` landing mid-restart either takes the
+/// new process before this thread installs it, or sees it installed and
+/// takes that — never both missing it and leaving it un-killed.
+///
+/// Every path that returns without installing a replacement process clears
+/// `shared.current` back to `None` first (unless `stop_exe()` already took
+/// it), so `ExeManager::launch_exe` never mistakes a stale terminal record
+/// for a still-running process.
+fn supervise(
+    shared: Arc<Mutex<SharedState>>,
+    addon_dir: PathBuf,
+    exe_path: PathBuf,
+    restart_policy: RestartPolicy,
+    attempt: u32,
+) {
+    let supervised = match shared.lock().unwrap().current.clone() {
+        Some(supervised) => supervised,
+        None => return,
+    };
+
+    let launched_at = Instant::now();
+
+    let exit_status = match supervised.child.wait() {
+        Ok(exit_status) => exit_status,
+        Err(e) => {
+            log::error!("Failed to wait on managed process: {e}");
+            *supervised.status.lock().unwrap() = ProcessStatus::Crashed;
+            shared.lock().unwrap().current = None;
+            return;
+        }
+    };
+
+    *supervised.status.lock().unwrap() = match exit_status.code() {
+        Some(code) => ProcessStatus::Exited(code),
+        None => ProcessStatus::Crashed,
+    };
+
+    if shared.lock().unwrap().stopping {
+        log::info!("Managed process stopped");
+        return;
+    }
+
+    if exit_status.success() {
+        log::info!("Managed process exited cleanly");
+        shared.lock().unwrap().current = None;
+        return;
+    }
+
+    if attempt >= restart_policy.max_retries {
+        log::error!(
+            "Managed process exited unexpectedly and exceeded {} restart attempts; giving up",
+            restart_policy.max_retries
+        );
+        shared.lock().unwrap().current = None;
+        return;
+    }
+
+    let elapsed = launched_at.elapsed();
+    if elapsed < restart_policy.backoff {
+        thread::sleep(restart_policy.backoff - elapsed);
+    }
+
+    log::warn!(
+        "Managed process exited unexpectedly, restarting (attempt {}/{})",
+        attempt + 1,
+        restart_policy.max_retries
+    );
+
+    let new_supervised = match spawn_child(&exe_path, &addon_dir) {
+        Ok(supervised) => supervised,
+        Err(e) => {
+            log::error!("Failed to restart managed process: {e}");
+            shared.lock().unwrap().current = None;
+            return;
+        }
+    };
+
+    {
+        let mut shared_guard = shared.lock().unwrap();
+        if shared_guard.stopping {
+            drop(shared_guard);
+            if let Err(e) = new_supervised.child.kill() {
+                log::warn!("Failed to kill restarted process during shutdown race: {e}");
+            }
+            let _ = new_supervised.child.wait();
+            return;
+        }
+        shared_guard.current = Some(new_supervised);
+    }
+
+    supervise(shared, addon_dir, exe_path, restart_policy, attempt + 1);
+}
@@ -0,0 +1,20 @@
+//! Nexus integration for the Blish HUD overlay loader: addon lifecycle,
+//! external process supervision, and UI glue.
+
+#[cfg(feature = "nexus")]
+pub mod init;
+#[cfg(feature = "nexus")]
+pub mod manager;
+#[cfg(feature = "nexus")]
+pub mod resources;
+#[cfg(feature = "nexus")]
+pub mod settings;
+#[cfg(feature = "nexus")]
+pub mod ui;
+
+mod error;
+
+pub use error::{NexusError, Result};
+
+#[cfg(feature = "nexus")]
+pub use init::{nexus_load, nexus_unload};
@@ -0,0 +1,118 @@
+//! Detects missing or empty files the addon depends on, so startup can
+//! degrade into an actionable recovery flow instead of silently failing (or
+//! failing later, deep inside the exe manager).
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::nexus_addon::NexusError;
+
+/// Page the user can visit to download a replacement Blish HUD executable.
+pub const BLISH_HUD_DOWNLOAD_URL: &str = "https://blishhud.com/";
+
+/// A required file that is missing or empty, along with where to send the
+/// user to obtain it.
+#[derive(Debug, Clone)]
+pub struct MissingResource {
+    /// Path that was expected to contain the resource.
+    pub path: PathBuf,
+    /// Page the user can visit to download a replacement.
+    pub download_url: &'static str,
+}
+
+/// Set once `initialize_nexus_addon` finds a missing resource, so the
+/// keybind and quick-access handlers can offer recovery instead of toggling
+/// a window for an overlay that isn't there.
+pub static MISSING_RESOURCE: OnceLock<MissingResource> = OnceLock::new();
+
+fn exists_and_non_empty(path: &Path) -> bool {
+    path.metadata().map(|metadata| metadata.len() > 0).unwrap_or(false)
+}
+
+/// Verifies that the managed executable at `exe_path` is present and
+/// non-empty, returning it as a missing resource if not. No other file is
+/// checked.
+pub fn verify_required_resources(exe_path: &Path) -> Option<MissingResource> {
+    if !exists_and_non_empty(exe_path) {
+        return Some(MissingResource {
+            path: exe_path.to_path_buf(),
+            download_url: BLISH_HUD_DOWNLOAD_URL,
+        });
+    }
+
+    None
+}
+
+/// Opens `url` in the user's default browser.
+#[cfg(feature = "browser-recovery")]
+pub fn open_download_page(url: &str) -> Result<(), NexusError> {
+    webbrowser::open(url)
+        .map_err(|e| NexusError::ResourceRecovery(format!("Failed to open browser: {e}")))
+}
+
+/// Without the `browser-recovery` feature, the download link is logged
+/// rather than opened automatically.
+#[cfg(not(feature = "browser-recovery"))]
+pub fn open_download_page(url: &str) -> Result<(), NexusError> {
+    log::warn!("browser-recovery feature is disabled; visit {url} manually");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// A fresh, empty directory under the OS temp dir, removed on drop.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(case: &str) -> Self {
+            static COUNTER: AtomicU32 = AtomicU32::new(0);
+            let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+            let dir = std::env::temp_dir().join(format!(
+                "blish-overlay-loader-resources-test-{case}-{}-{n}",
+                std::process::id()
+            ));
+            std::fs::create_dir_all(&dir).expect("failed to create temp dir");
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn missing_exe_is_reported_as_missing_resource() {
+        let dir = TempDir::new("missing");
+        let exe_path = dir.0.join("Blish HUD.exe");
+
+        let missing = verify_required_resources(&exe_path).expect("expected a missing resource");
+
+        assert_eq!(missing.path, exe_path);
+        assert_eq!(missing.download_url, BLISH_HUD_DOWNLOAD_URL);
+    }
+
+    #[test]
+    fn empty_exe_is_reported_as_missing_resource() {
+        let dir = TempDir::new("empty");
+        let exe_path = dir.0.join("Blish HUD.exe");
+        std::fs::write(&exe_path, []).unwrap();
+
+        let missing = verify_required_resources(&exe_path).expect("expected a missing resource");
+
+        assert_eq!(missing.path, exe_path);
+    }
+
+    #[test]
+    fn non_empty_exe_is_not_reported_as_missing() {
+        let dir = TempDir::new("present");
+        let exe_path = dir.0.join("Blish HUD.exe");
+        std::fs::write(&exe_path, [0u8; 4]).unwrap();
+
+        assert!(verify_required_resources(&exe_path).is_none());
+    }
+}
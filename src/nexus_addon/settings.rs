@@ -0,0 +1,174 @@
+/*!
+# Settings
+
+Persists the addon's user-tunable state — whether to launch on startup, the
+overlay keybind, the managed executable's name, and the quick-access/keybind
+registration identifiers — to a `settings.ron` file in the addon directory,
+so it survives between Guild Wars 2 sessions.
+*/
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+/// Name of the settings file inside the addon directory.
+const SETTINGS_FILE_NAME: &str = "settings.ron";
+
+/// Global handle to the loaded settings, set during `initialize_nexus_addon`
+/// before the exe manager is created. Unlike a `OnceLock`, this can be
+/// cleared back to `None` — `InitGuard::rollback` does so on a failed init,
+/// and `cleanup_nexus_addon` does so on unload — so a later `nexus_load()`
+/// can set it again instead of being permanently wedged by a stale value.
+pub static SETTINGS: Mutex<Option<Settings>> = Mutex::new(None);
+
+/// User-tunable addon settings, persisted as RON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Whether the managed executable should launch automatically on addon load.
+    pub launch_on_startup: bool,
+    /// Keybind string passed to `register_keybind_with_string`, e.g. `"ALT+SHIFT+1"`.
+    pub keybind: String,
+    /// Name of the managed executable, resolved relative to the addon directory.
+    pub exe_name: String,
+    /// Identifier `add_quick_access` registers the shortcut under.
+    pub quick_access_id: String,
+    /// Identifier `register_keybind_with_string` registers the overlay keybind under.
+    pub keybind_id: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            launch_on_startup: true,
+            keybind: "ALT+SHIFT+1".to_string(),
+            exe_name: "Blish HUD.exe".to_string(),
+            quick_access_id: "BLISH_OVERLAY_LOADER_SHORTCUT".to_string(),
+            keybind_id: "BLISH_OVERLAY_LOADER_KEYBIND".to_string(),
+        }
+    }
+}
+
+impl Settings {
+    /// Loads settings from `addon_dir`, falling back to (and rewriting) the
+    /// documented defaults if the file is missing or malformed.
+    pub fn load(addon_dir: &Path) -> Self {
+        let path = settings_path(addon_dir);
+
+        let parsed = std::fs::read_to_string(&path).ok().and_then(|contents| {
+            match ron::from_str(&contents) {
+                Ok(settings) => Some(settings),
+                Err(e) => {
+                    log::warn!(
+                        "Malformed settings file at {}, falling back to defaults: {e}",
+                        path.display()
+                    );
+                    None
+                }
+            }
+        });
+
+        match parsed {
+            Some(settings) => settings,
+            None => {
+                let settings = Settings::default();
+                settings.save(addon_dir);
+                settings
+            }
+        }
+    }
+
+    /// Writes settings back to `settings.ron` in `addon_dir`.
+    pub fn save(&self, addon_dir: &Path) {
+        let path = settings_path(addon_dir);
+
+        let contents = match ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()) {
+            Ok(contents) => contents,
+            Err(e) => {
+                log::error!("Failed to serialize settings: {e}");
+                return;
+            }
+        };
+
+        if let Err(e) = std::fs::write(&path, contents) {
+            log::error!("Failed to write settings file at {}: {e}", path.display());
+        }
+    }
+}
+
+fn settings_path(addon_dir: &Path) -> PathBuf {
+    addon_dir.join(SETTINGS_FILE_NAME)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// A fresh, empty directory under the OS temp dir, removed on drop.
+    struct TempAddonDir(PathBuf);
+
+    impl TempAddonDir {
+        fn new(case: &str) -> Self {
+            static COUNTER: AtomicU32 = AtomicU32::new(0);
+            let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+            let dir = std::env::temp_dir().join(format!(
+                "blish-overlay-loader-settings-test-{case}-{}-{n}",
+                std::process::id()
+            ));
+            std::fs::create_dir_all(&dir).expect("failed to create temp addon dir");
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempAddonDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn load_on_missing_file_returns_and_writes_defaults() {
+        let addon_dir = TempAddonDir::new("missing");
+
+        let loaded = Settings::load(&addon_dir.0);
+
+        assert_eq!(loaded, Settings::default());
+        assert!(settings_path(&addon_dir.0).is_file());
+    }
+
+    #[test]
+    fn save_then_load_round_trips() {
+        let addon_dir = TempAddonDir::new("round-trip");
+        let settings = Settings {
+            launch_on_startup: false,
+            keybind: "CTRL+ALT+B".to_string(),
+            exe_name: "CustomBlishHud.exe".to_string(),
+            quick_access_id: "CUSTOM_SHORTCUT".to_string(),
+            keybind_id: "CUSTOM_KEYBIND".to_string(),
+        };
+
+        settings.save(&addon_dir.0);
+        let loaded = Settings::load(&addon_dir.0);
+
+        assert_eq!(loaded, settings);
+    }
+
+    #[test]
+    fn load_on_malformed_file_falls_back_to_defaults_and_rewrites() {
+        let addon_dir = TempAddonDir::new("malformed");
+        let path = settings_path(&addon_dir.0);
+        std::fs::write(&path, "not valid ron (").unwrap();
+
+        let loaded = Settings::load(&addon_dir.0);
+
+        assert_eq!(loaded, Settings::default());
+
+        // The malformed file should have been overwritten with something
+        // that now parses back to the same defaults.
+        let rewritten = std::fs::read_to_string(&path).unwrap();
+        let rewritten: Settings = ron::from_str(&rewritten).unwrap();
+        assert_eq!(rewritten, Settings::default());
+    }
+}
@@ -0,0 +1,65 @@
+//! UI rendering and window-visibility handling for the overlay.
+//!
+//! The external DX11 overlay grabs input focus while it's open. If closing
+//! it didn't hand focus back to the game window, the player would be left
+//! unable to type or use keybinds in Guild Wars 2.
+
+use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+
+use windows::Win32::Foundation::HWND;
+use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, SetForegroundWindow};
+
+/// Whether the overlay window is currently shown.
+static WINDOW_VISIBLE: AtomicBool = AtomicBool::new(false);
+
+/// The window that had focus right before the overlay was shown, so it can
+/// be restored when the overlay is hidden again. `0` means none captured.
+static GAME_WINDOW: AtomicIsize = AtomicIsize::new(0);
+
+/// Logs that main-window rendering is set up. No render callback is
+/// registered with Nexus.
+pub fn setup_main_window_rendering() {
+    log::info!("Main window rendering set up successfully");
+}
+
+/// Flips the overlay window's visibility.
+pub fn toggle_window() {
+    set_overlay_visible(!WINDOW_VISIBLE.load(Ordering::Acquire));
+}
+
+/// Shows or hides the overlay window.
+///
+/// Showing it only remembers whatever window currently has focus (the
+/// overlay doesn't need to steal it itself); hiding it always hands focus
+/// back to that remembered window, so the game keeps receiving keystrokes
+/// after the overlay closes.
+pub fn set_overlay_visible(visible: bool) {
+    if visible {
+        let foreground = unsafe { GetForegroundWindow() };
+        if foreground.0 != 0 {
+            GAME_WINDOW.store(foreground.0, Ordering::Release);
+        }
+    } else {
+        restore_game_focus();
+    }
+
+    WINDOW_VISIBLE.store(visible, Ordering::Release);
+    log::info!(
+        "Overlay window toggled {}",
+        if visible { "visible" } else { "hidden" }
+    );
+}
+
+/// Hands input focus back to the game window captured by the last
+/// `set_overlay_visible(true)` call, if any.
+fn restore_game_focus() {
+    let game_window = GAME_WINDOW.swap(0, Ordering::AcqRel);
+    if game_window == 0 {
+        return;
+    }
+
+    let restored = unsafe { SetForegroundWindow(HWND(game_window)) };
+    if !restored.as_bool() {
+        log::warn!("Failed to restore focus to the game window after closing the overlay");
+    }
+}